@@ -0,0 +1,44 @@
+use eyre::Result;
+use tracing::info;
+
+use crate::misc::shared_init::{init_deps, ConnOpts};
+
+/// `mevlog watch` — subscribes to new blocks (and optionally pending
+/// mempool transactions) over a WebSocket/IPC provider as they land,
+/// instead of querying a historical block range.
+///
+/// Logs a one-line summary per block/tx. Blocks and transactions are not
+/// passed through the decoding/MEV-detection pipeline that the historical
+/// commands use (tx simulation, profit/gas accounting, etc.).
+#[derive(Debug, Clone, clap::Parser)]
+pub struct WatchOpts {
+    #[clap(flatten)]
+    pub conn_opts: ConnOpts,
+
+    #[arg(long, help = "Also watch pending mempool transactions")]
+    pub pending_txs: bool,
+}
+
+pub async fn run(opts: WatchOpts) -> Result<()> {
+    let deps = init_deps(&opts.conn_opts).await?;
+
+    if opts.pending_txs {
+        tokio::try_join!(
+            deps.watch_blocks(|block| log_block(deps.chain.name(), &block)),
+            deps.watch_pending_transactions(|tx| log_pending_tx(deps.chain.name(), &tx)),
+        )?;
+
+        return Ok(());
+    }
+
+    deps.watch_blocks(|block| log_block(deps.chain.name(), &block))
+        .await
+}
+
+fn log_block(chain_name: &str, block: &alloy::rpc::types::Block) {
+    info!("[{chain_name}] New block #{}", block.header.number);
+}
+
+fn log_pending_tx(chain_name: &str, tx: &alloy::rpc::types::Transaction) {
+    info!("[{chain_name}] New pending tx {}", tx.inner.tx_hash());
+}
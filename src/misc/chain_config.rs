@@ -0,0 +1,166 @@
+use std::{fs, path::Path};
+
+use eyre::Result;
+use revm::primitives::Address;
+use serde::Deserialize;
+
+use super::shared_init::config_path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomChainConfig {
+    pub chain_id: u64,
+    pub name: String,
+    pub price_oracle: Address,
+    pub etherscan_url: String,
+    pub currency_symbol: String,
+    pub cryo_cache_dir_name: String,
+}
+
+const CHAINS_CONFIG_FILE: &str = "chains.toml";
+
+#[derive(Debug, Deserialize)]
+struct ChainsConfigFile {
+    #[serde(default, rename = "chain")]
+    chains: Vec<CustomChainEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CustomChainEntry {
+    chain_id: u64,
+    name: String,
+    price_oracle: Address,
+    etherscan_url: String,
+    currency_symbol: String,
+    cryo_cache_dir_name: Option<String>,
+}
+
+/// Loads user-defined chains from `~/.mevlog/chains.toml`, e.g.:
+///
+/// ```toml
+/// [[chain]]
+/// chain_id = 1337
+/// name = "my-devnet"
+/// price_oracle = "0x0000000000000000000000000000000000000000"
+/// etherscan_url = "https://explorer.example.com"
+/// currency_symbol = "ETH"
+/// ```
+///
+/// Returns an empty list when the file doesn't exist.
+pub fn load_custom_chains() -> Result<Vec<CustomChainConfig>> {
+    load_custom_chains_from(&config_path().join(CHAINS_CONFIG_FILE))
+}
+
+fn load_custom_chains_from(path: &Path) -> Result<Vec<CustomChainConfig>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let raw = fs::read_to_string(path)?;
+    let parsed: ChainsConfigFile = toml::from_str(&raw)?;
+
+    Ok(parsed
+        .chains
+        .into_iter()
+        .map(|entry| CustomChainConfig {
+            cryo_cache_dir_name: entry
+                .cryo_cache_dir_name
+                .unwrap_or_else(|| entry.chain_id.to_string()),
+            chain_id: entry.chain_id,
+            name: entry.name,
+            price_oracle: entry.price_oracle,
+            etherscan_url: entry.etherscan_url,
+            currency_symbol: entry.currency_symbol,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use revm::primitives::address;
+
+    use super::*;
+
+    fn temp_toml_path() -> std::path::PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        std::env::temp_dir().join(format!(
+            "mevlog-chains-test-{}-{}.toml",
+            std::process::id(),
+            id
+        ))
+    }
+
+    #[test]
+    fn missing_file_returns_empty_list() {
+        let path = temp_toml_path();
+
+        assert_eq!(load_custom_chains_from(&path).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn malformed_toml_returns_err() {
+        let path = temp_toml_path();
+        fs::write(&path, "this is not valid toml =").unwrap();
+
+        let result = load_custom_chains_from(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_custom_chain_and_defaults_cryo_cache_dir_name() {
+        let path = temp_toml_path();
+        fs::write(
+            &path,
+            r#"
+            [[chain]]
+            chain_id = 1337
+            name = "my-devnet"
+            price_oracle = "0x0000000000000000000000000000000000000000"
+            etherscan_url = "https://explorer.example.com"
+            currency_symbol = "ETH"
+            "#,
+        )
+        .unwrap();
+
+        let chains = load_custom_chains_from(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(chains.len(), 1);
+        let chain = &chains[0];
+        assert_eq!(chain.chain_id, 1337);
+        assert_eq!(chain.name, "my-devnet");
+        assert_eq!(
+            chain.price_oracle,
+            address!("0x0000000000000000000000000000000000000000")
+        );
+        assert_eq!(chain.cryo_cache_dir_name, "1337");
+    }
+
+    #[test]
+    fn explicit_cryo_cache_dir_name_is_preserved() {
+        let path = temp_toml_path();
+        fs::write(
+            &path,
+            r#"
+            [[chain]]
+            chain_id = 1337
+            name = "my-devnet"
+            price_oracle = "0x0000000000000000000000000000000000000000"
+            etherscan_url = "https://explorer.example.com"
+            currency_symbol = "ETH"
+            cryo_cache_dir_name = "custom_dir"
+            "#,
+        )
+        .unwrap();
+
+        let chains = load_custom_chains_from(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(chains[0].cryo_cache_dir_name, "custom_dir");
+    }
+}
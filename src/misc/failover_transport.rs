@@ -0,0 +1,108 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use alloy::{
+    rpc::{
+        client::RpcClient,
+        json_rpc::{RequestPacket, ResponsePacket},
+    },
+    transports::{TransportError, TransportFut},
+};
+use tower::Service;
+use tracing::debug;
+
+/// A `Transport` that rotates across multiple RPC endpoints, each already
+/// wrapped in its own `RetryBackoffLayer`. Each request starts at the next
+/// endpoint in round-robin order, so healthy endpoints share the load. A
+/// request only moves on to the following endpoint once the current one has
+/// returned an error after its own retries.
+#[derive(Clone)]
+pub struct FailoverTransport {
+    endpoints: Arc<Vec<(String, RpcClient)>>,
+    next: Arc<AtomicUsize>,
+}
+
+impl FailoverTransport {
+    pub fn new(endpoints: Vec<(String, RpcClient)>) -> Self {
+        assert!(!endpoints.is_empty(), "at least one RPC endpoint required");
+
+        Self {
+            endpoints: Arc::new(endpoints),
+            next: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+impl Service<RequestPacket> for FailoverTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        let endpoints = self.endpoints.clone();
+        let start = rotate_start(&self.next, endpoints.len());
+
+        Box::pin(async move {
+            let mut last_err = None;
+
+            for offset in 0..endpoints.len() {
+                let (rpc_url, client) = &endpoints[(start + offset) % endpoints.len()];
+                debug!("Sending request via RPC endpoint {rpc_url}");
+
+                match client.clone().call(req.clone()).await {
+                    Ok(response) => return Ok(response),
+                    Err(err) => {
+                        debug!("RPC endpoint {rpc_url} failed, failing over: {err}");
+                        last_err = Some(err);
+                    }
+                }
+            }
+
+            Err(last_err.expect("at least one RPC endpoint configured"))
+        })
+    }
+}
+
+// Advances the round-robin counter and returns the endpoint index the next
+// request should start at, wrapping around `len`.
+fn rotate_start(next: &AtomicUsize, len: usize) -> usize {
+    next.fetch_add(1, Ordering::Relaxed) % len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate_start_cycles_through_all_endpoints() {
+        let next = AtomicUsize::new(0);
+
+        let starts: Vec<usize> = (0..6).map(|_| rotate_start(&next, 3)).collect();
+
+        assert_eq!(starts, vec![0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn rotate_start_with_single_endpoint_always_zero() {
+        let next = AtomicUsize::new(0);
+
+        for _ in 0..3 {
+            assert_eq!(rotate_start(&next, 1), 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one RPC endpoint required")]
+    fn new_panics_on_empty_endpoints() {
+        FailoverTransport::new(vec![]);
+    }
+}
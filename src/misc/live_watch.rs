@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use alloy::{
+    providers::Provider,
+    rpc::types::{Block, Transaction},
+};
+use eyre::Result;
+use futures::StreamExt;
+use tracing::debug;
+
+use crate::GenericProvider;
+
+/// Subscribes to newly mined blocks over a pub-sub capable transport and
+/// invokes `on_block` for each one as it arrives, instead of querying
+/// historical block ranges.
+pub async fn watch_blocks<F>(provider: Arc<GenericProvider>, mut on_block: F) -> Result<()>
+where
+    F: FnMut(Block) + Send,
+{
+    let mut blocks = provider.subscribe_blocks().await?.into_stream();
+
+    while let Some(block) = blocks.next().await {
+        debug!("New block received: {}", block.header.number);
+        on_block(block);
+    }
+
+    Ok(())
+}
+
+/// Subscribes to pending transactions as they hit the mempool and invokes
+/// `on_tx` for each one.
+pub async fn watch_pending_transactions<F>(
+    provider: Arc<GenericProvider>,
+    mut on_tx: F,
+) -> Result<()>
+where
+    F: FnMut(Transaction) + Send,
+{
+    let mut pending = provider
+        .subscribe_full_pending_transactions()
+        .await?
+        .into_stream();
+
+    while let Some(tx) = pending.next().await {
+        debug!("New pending tx received: {}", tx.inner.tx_hash());
+        on_tx(tx);
+    }
+
+    Ok(())
+}
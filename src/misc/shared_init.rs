@@ -3,18 +3,21 @@ use std::{path::PathBuf, str::FromStr, sync::Arc};
 use alloy::{
     providers::{Provider, ProviderBuilder},
     rpc::client::RpcClient,
-    transports::layers::RetryBackoffLayer,
+    transports::{ipc::IpcConnect, layers::RetryBackoffLayer, ws::WsConnect},
 };
 use eyre::Result;
 use revm::primitives::{address, Address};
 use sqlx::SqlitePool;
 use tokio::sync::mpsc::UnboundedSender;
-use tracing::debug;
+use tracing::{debug, warn};
 
 use super::{
+    chain_config::{load_custom_chains, CustomChainConfig},
     database::sqlite_conn,
     db_actions::db_file_exists,
     ens_utils::start_ens_lookup_worker,
+    failover_transport::FailoverTransport,
+    live_watch,
     symbol_utils::{start_symbols_lookup_worker, SymbolLookupWorker},
 };
 use crate::{misc::db_actions::download_db_file, GenericProvider};
@@ -32,6 +35,7 @@ pub enum EVMChainType {
     Linea,
     Scroll,
     Fantom,
+    Custom(CustomChainConfig),
     Unknown(u64),
 }
 
@@ -55,6 +59,7 @@ impl EVMChainType {
             EVMChainType::Linea => 59144,
             EVMChainType::Scroll => 534352,
             EVMChainType::Fantom => 250,
+            EVMChainType::Custom(config) => config.chain_id,
             EVMChainType::Unknown(chain_id) => *chain_id,
         }
     }
@@ -72,12 +77,16 @@ impl EVMChainType {
             EVMChainType::Linea => "linea",
             EVMChainType::Scroll => "scroll",
             EVMChainType::Fantom => "fantom",
+            EVMChainType::Custom(config) => &config.name,
             EVMChainType::Unknown(_) => "unknown",
         }
     }
 
+    /// Built-in chains, plus any user-defined chains registered in
+    /// `chains.toml` (see `chain_config::load_custom_chains`). A malformed
+    /// config file is logged and ignored rather than failing the whole run.
     pub fn supported() -> Vec<Self> {
-        vec![
+        let mut chains = vec![
             EVMChainType::Mainnet,
             EVMChainType::Base,
             EVMChainType::BSC,
@@ -89,7 +98,14 @@ impl EVMChainType {
             EVMChainType::Linea,
             EVMChainType::Scroll,
             EVMChainType::Fantom,
-        ]
+        ];
+
+        match load_custom_chains() {
+            Ok(custom_chains) => chains.extend(custom_chains.into_iter().map(EVMChainType::Custom)),
+            Err(err) => tracing::warn!("Failed to load chains.toml, ignoring: {err}"),
+        }
+
+        chains
     }
 
     pub fn supported_chains_text() -> String {
@@ -102,7 +118,8 @@ impl EVMChainType {
         format!(
             r#"Currently supported EVM chains:
 {chains}
-Visit https://github.com/pawurb/mevlog-rs/issues/9 to add more."#
+Register additional ones in {}/chains.toml, or visit https://github.com/pawurb/mevlog-rs/issues/9 to add more."#,
+            config_path().display()
         )
     }
 }
@@ -148,6 +165,7 @@ impl EVMChain {
             EVMChainType::BSC => "bnb".to_string(),
             EVMChainType::Scroll => "network_534352".to_string(),
             EVMChainType::Fantom => "network_250".to_string(),
+            EVMChainType::Custom(ref config) => config.cryo_cache_dir_name.clone(),
             EVMChainType::Unknown(chain_id) => format!("network_{}", chain_id),
             _ => self.chain_id().to_string(),
         }
@@ -168,6 +186,7 @@ impl EVMChain {
             EVMChainType::Linea => address!("0x3c6Cd9Cc7c7a4c2Cf5a82734CD249D7D593354dA"),
             EVMChainType::Scroll => address!("0x6bF14CB0A831078629D993FDeBcB182b21A8774C"),
             EVMChainType::Fantom => address!("0x11DdD3d147E5b83D01cee7070027092397d63658"),
+            EVMChainType::Custom(ref config) => config.price_oracle,
             EVMChainType::Unknown(_) => address!("0x0000000000000000000000000000000000000000"),
         }
     }
@@ -185,6 +204,7 @@ impl EVMChain {
             EVMChainType::Linea => "https://lineascan.build",
             EVMChainType::Scroll => "https://scrollscan.com",
             EVMChainType::Fantom => "https://explorer.fantom.network",
+            EVMChainType::Custom(ref config) => &config.etherscan_url,
             EVMChainType::Unknown(_) => "https://etherscan.io",
         }
     }
@@ -196,21 +216,110 @@ impl EVMChain {
             EVMChainType::Avalanche => "AVAX",
             EVMChainType::Metis => "METIS",
             EVMChainType::Fantom => "FTM",
+            EVMChainType::Custom(ref config) => &config.currency_symbol,
             _ => "ETH",
         }
     }
 }
 
+// The transport backing the active provider. Only pub-sub capable transports
+// (WS and IPC) can drive `SharedDeps::watch_blocks`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportKind {
+    Http,
+    Ws,
+    Ipc,
+}
+
+impl TransportKind {
+    pub fn supports_subscriptions(&self) -> bool {
+        matches!(self, TransportKind::Ws | TransportKind::Ipc)
+    }
+
+    fn from_rpc_url(rpc_url: &str) -> Self {
+        if rpc_url.starts_with("ws://") || rpc_url.starts_with("wss://") {
+            TransportKind::Ws
+        } else if rpc_url.starts_with("ipc://") || is_ipc_path(rpc_url) {
+            TransportKind::Ipc
+        } else {
+            TransportKind::Http
+        }
+    }
+}
+
+// A bare `rpc_url` that isn't an HTTP(S) URL is taken as a path to a
+// Unix-domain socket when it starts with `/` or `~` or ends in `.ipc`, e.g.
+// `~/.ethereum/geth.ipc`. Anything else is not treated as an IPC path.
+fn is_ipc_path(rpc_url: &str) -> bool {
+    !rpc_url.starts_with("http://")
+        && !rpc_url.starts_with("https://")
+        && (rpc_url.starts_with('/') || rpc_url.starts_with('~') || rpc_url.ends_with(".ipc"))
+}
+
+// Resolves an `ipc://` prefix and a leading `~/` home-directory shorthand
+// into a concrete socket path.
+fn resolve_ipc_path(rpc_url: &str) -> PathBuf {
+    let path = rpc_url.strip_prefix("ipc://").unwrap_or(rpc_url);
+
+    match path.strip_prefix("~/") {
+        Some(rest) => home::home_dir().unwrap().join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
 pub struct SharedDeps {
     pub sqlite: SqlitePool,
     pub ens_lookup_worker: UnboundedSender<Address>,
     pub symbols_lookup_worker: SymbolLookupWorker,
     pub provider: Arc<GenericProvider>,
     pub chain: EVMChain,
+    pub transport: TransportKind,
+}
+
+impl SharedDeps {
+    /// Subscribes to newly mined blocks and invokes `on_block` for each one
+    /// as it lands, instead of polling a historical block range. Requires a
+    /// pub-sub capable transport (`--rpc-url ws://`/`wss://` or an IPC
+    /// socket path).
+    pub async fn watch_blocks<F>(&self, on_block: F) -> Result<()>
+    where
+        F: FnMut(alloy::rpc::types::Block) + Send,
+    {
+        ensure_subscribable(self.transport)?;
+
+        live_watch::watch_blocks(self.provider.clone(), on_block).await
+    }
+
+    /// Subscribes to pending transactions as they hit the mempool and
+    /// invokes `on_tx` for each one. Requires the same pub-sub capable
+    /// transport as `watch_blocks`.
+    pub async fn watch_pending_transactions<F>(&self, on_tx: F) -> Result<()>
+    where
+        F: FnMut(alloy::rpc::types::Transaction) + Send,
+    {
+        ensure_subscribable(self.transport)?;
+
+        live_watch::watch_pending_transactions(self.provider.clone(), on_tx).await
+    }
+}
+
+// Guard shared by `watch_blocks`/`watch_pending_transactions`: both require a
+// pub-sub capable transport (`--rpc-url ws://`/`wss://` or an IPC socket
+// path), since plain HTTP can't stream subscriptions.
+fn ensure_subscribable(transport: TransportKind) -> Result<()> {
+    if !transport.supports_subscriptions() {
+        return Err(eyre::eyre!(
+            "Live watch mode requires a WebSocket or IPC provider, use --rpc-url ws://..., wss://... or a local IPC socket path"
+        ));
+    }
+
+    Ok(())
 }
 
 pub async fn init_deps(conn_opts: &ConnOpts) -> Result<SharedDeps> {
-    if conn_opts.rpc_url.is_none() {
+    let rpc_urls = conn_opts.rpc_urls();
+
+    if rpc_urls.is_empty() {
         return Err(eyre::eyre!(
             "Missing provider URL, use --rpc-url or set ETH_RPC_URL env var"
         ));
@@ -225,11 +334,12 @@ pub async fn init_deps(conn_opts: &ConnOpts) -> Result<SharedDeps> {
     let sqlite_conn = sqlite_conn(None).await?;
     let ens_lookup_worker = start_ens_lookup_worker(conn_opts);
     let symbols_lookup_worker = start_symbols_lookup_worker(conn_opts);
+    let transport = TransportKind::from_rpc_url(&rpc_urls[0]);
     let provider = init_provider(conn_opts).await?;
     let provider = Arc::new(provider);
 
     let chain_id = provider.get_chain_id().await?;
-    let chain = EVMChain::new(chain_id, conn_opts.rpc_url.clone().unwrap())?;
+    let chain = EVMChain::new(chain_id, rpc_urls[0].clone())?;
 
     Ok(SharedDeps {
         sqlite: sqlite_conn,
@@ -237,25 +347,88 @@ pub async fn init_deps(conn_opts: &ConnOpts) -> Result<SharedDeps> {
         symbols_lookup_worker,
         provider,
         chain,
+        transport,
     })
 }
 
-pub async fn init_provider(conn_opts: &ConnOpts) -> Result<GenericProvider> {
+fn http_client(rpc_url: &str) -> Result<RpcClient> {
     let max_retry = 10;
     let backoff = 1000;
     let cups = 100;
     let retry_layer = RetryBackoffLayer::new(max_retry, backoff, cups);
 
-    if let Some(rpc_url) = &conn_opts.rpc_url {
-        debug!("Initializing HTTP provider");
-        let client = RpcClient::builder()
-            .layer(retry_layer)
-            .http(rpc_url.parse()?);
+    Ok(RpcClient::builder()
+        .layer(retry_layer)
+        .http(rpc_url.parse()?))
+}
+
+pub async fn init_provider(conn_opts: &ConnOpts) -> Result<GenericProvider> {
+    let rpc_urls = conn_opts.rpc_urls();
 
-        Ok(ProviderBuilder::new().on_client(client))
-    } else {
+    if rpc_urls.is_empty() {
         unreachable!()
     }
+
+    match TransportKind::from_rpc_url(&rpc_urls[0]) {
+        TransportKind::Ws => {
+            if rpc_urls.len() > 1 {
+                warn!(
+                    "Failover is only supported for HTTP endpoints, ignoring {} additional endpoint(s) and using {}",
+                    rpc_urls.len() - 1,
+                    rpc_urls[0]
+                );
+            }
+
+            debug!("Initializing WebSocket provider");
+            let ws_connect = WsConnect::new(rpc_urls[0].clone());
+            return Ok(ProviderBuilder::new().on_ws(ws_connect).await?);
+        }
+        TransportKind::Ipc => {
+            if rpc_urls.len() > 1 {
+                warn!(
+                    "Failover is only supported for HTTP endpoints, ignoring {} additional endpoint(s) and using {}",
+                    rpc_urls.len() - 1,
+                    rpc_urls[0]
+                );
+            }
+
+            debug!("Initializing IPC provider");
+            let ipc_connect = IpcConnect::new(resolve_ipc_path(&rpc_urls[0]));
+            return Ok(ProviderBuilder::new().on_ipc(ipc_connect).await?);
+        }
+        TransportKind::Http => {}
+    }
+
+    if rpc_urls.len() == 1 {
+        debug!("Initializing HTTP provider");
+        let client = http_client(&rpc_urls[0])?;
+        return Ok(ProviderBuilder::new().on_client(client));
+    }
+
+    let mismatched_scheme: Vec<&str> = rpc_urls
+        .iter()
+        .map(String::as_str)
+        .filter(|rpc_url| TransportKind::from_rpc_url(rpc_url) != TransportKind::Http)
+        .collect();
+
+    if !mismatched_scheme.is_empty() {
+        return Err(eyre::eyre!(
+            "Failover only supports a list of HTTP endpoints, found incompatible entries: {}",
+            mismatched_scheme.join(", ")
+        ));
+    }
+
+    debug!(
+        "Initializing HTTP provider with {} endpoints",
+        rpc_urls.len()
+    );
+    let endpoints = rpc_urls
+        .iter()
+        .map(|rpc_url| Ok((rpc_url.clone(), http_client(rpc_url)?)))
+        .collect::<Result<Vec<_>>>()?;
+    let client = RpcClient::builder().transport(FailoverTransport::new(endpoints), false);
+
+    Ok(ProviderBuilder::new().on_client(client))
 }
 
 pub fn config_path() -> PathBuf {
@@ -264,19 +437,168 @@ pub fn config_path() -> PathBuf {
 
 #[derive(Clone, Debug, clap::Parser)]
 pub struct ConnOpts {
-    #[arg(long, help = "The URL of the HTTP provider", env = "ETH_RPC_URL")]
+    #[arg(
+        long,
+        help = "The URL of the HTTP or WebSocket (ws://, wss://) provider, \
+        or a path to a local IPC socket (e.g. ~/.ethereum/geth.ipc). \
+        Pass a comma-separated list of HTTP URLs to fail over between them",
+        env = "ETH_RPC_URL"
+    )]
     pub rpc_url: Option<String>,
 
     #[arg(long, help = "EVM tracing mode ('revm' or 'rpc')")]
     pub trace: Option<TraceMode>,
 }
 
+impl ConnOpts {
+    /// Splits `rpc_url` on commas into the list of configured endpoints.
+    pub fn rpc_urls(&self) -> Vec<String> {
+        self.rpc_url
+            .as_deref()
+            .map(|rpc_url| {
+                rpc_url
+                    .split(',')
+                    .map(|url| url.trim().to_string())
+                    .filter(|url| !url.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Clone, clap::Parser)]
 pub enum TraceMode {
     Revm,
     RPC,
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conn_opts(rpc_url: Option<&str>) -> ConnOpts {
+        ConnOpts {
+            rpc_url: rpc_url.map(str::to_string),
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn rpc_urls_none_is_empty() {
+        assert_eq!(conn_opts(None).rpc_urls(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rpc_urls_splits_trims_and_drops_empty_segments() {
+        let opts = conn_opts(Some(" http://a.example , http://b.example ,, "));
+
+        assert_eq!(
+            opts.rpc_urls(),
+            vec![
+                "http://a.example".to_string(),
+                "http://b.example".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn rpc_urls_blank_value_is_empty() {
+        assert_eq!(conn_opts(Some(",")).rpc_urls(), Vec::<String>::new());
+        assert_eq!(conn_opts(Some("  ")).rpc_urls(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rpc_urls_single_value() {
+        assert_eq!(
+            conn_opts(Some("http://a.example")).rpc_urls(),
+            vec!["http://a.example".to_string()]
+        );
+    }
+
+    #[test]
+    fn transport_kind_detects_ws() {
+        assert_eq!(
+            TransportKind::from_rpc_url("ws://localhost:8546"),
+            TransportKind::Ws
+        );
+        assert_eq!(
+            TransportKind::from_rpc_url("wss://example.com"),
+            TransportKind::Ws
+        );
+    }
+
+    #[test]
+    fn transport_kind_detects_http() {
+        assert_eq!(
+            TransportKind::from_rpc_url("http://localhost:8545"),
+            TransportKind::Http
+        );
+        assert_eq!(
+            TransportKind::from_rpc_url("https://example.com"),
+            TransportKind::Http
+        );
+    }
+
+    #[test]
+    fn transport_kind_detects_ipc() {
+        assert_eq!(
+            TransportKind::from_rpc_url("ipc:///tmp/geth.ipc"),
+            TransportKind::Ipc
+        );
+        assert_eq!(
+            TransportKind::from_rpc_url("/tmp/geth.ipc"),
+            TransportKind::Ipc
+        );
+        assert_eq!(
+            TransportKind::from_rpc_url("~/.ethereum/geth.ipc"),
+            TransportKind::Ipc
+        );
+        assert_eq!(TransportKind::from_rpc_url("geth.ipc"), TransportKind::Ipc);
+    }
+
+    #[test]
+    fn is_ipc_path_rejects_http_and_https() {
+        assert!(!is_ipc_path("http://localhost:8545"));
+        assert!(!is_ipc_path("https://example.com"));
+    }
+
+    #[test]
+    fn resolve_ipc_path_strips_ipc_prefix() {
+        assert_eq!(
+            resolve_ipc_path("ipc:///tmp/geth.ipc"),
+            PathBuf::from("/tmp/geth.ipc")
+        );
+    }
+
+    #[test]
+    fn resolve_ipc_path_expands_home_shorthand() {
+        let expected = home::home_dir().unwrap().join(".ethereum/geth.ipc");
+
+        assert_eq!(resolve_ipc_path("~/.ethereum/geth.ipc"), expected);
+    }
+
+    #[test]
+    fn resolve_ipc_path_leaves_absolute_path_untouched() {
+        assert_eq!(
+            resolve_ipc_path("/tmp/geth.ipc"),
+            PathBuf::from("/tmp/geth.ipc")
+        );
+    }
+
+    #[test]
+    fn ensure_subscribable_rejects_http_transport() {
+        let err = ensure_subscribable(TransportKind::Http).unwrap_err();
+
+        assert!(err.to_string().contains("WebSocket or IPC"));
+    }
+
+    #[test]
+    fn ensure_subscribable_accepts_ws_and_ipc_transports() {
+        assert!(ensure_subscribable(TransportKind::Ws).is_ok());
+        assert!(ensure_subscribable(TransportKind::Ipc).is_ok());
+    }
+}
+
 impl FromStr for TraceMode {
     type Err = eyre::Error;
 